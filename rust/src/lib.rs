@@ -1,9 +1,10 @@
 use wasm_bindgen::prelude::*;
-use js_sys::{Array, Object, Reflect};
+use js_sys::{Array, Object, Reflect, JSON};
 use std::collections::HashMap;
 
 const CHUNK_SIZE: usize = 32768;  // Optimized chunk size for L1 cache
 const SIMD_BATCH_SIZE: usize = 8;  // Process 8 items at once for SIMD-like operations
+const DEFAULT_MAX_DEPTH: u32 = 40;  // Recursion cutoff for nested/recursive schemas
 
 #[wasm_bindgen(start)]
 pub fn init() {
@@ -28,6 +29,8 @@ pub struct DhiCore {
     union_caches: HashMap<String, UnionCache>,
     // Presence bitmap optimization for asymmetric structures
     presence_bitmap_cache: HashMap<String, u32>, // field -> bit position
+    // Level cutoff for recursive validation (nested objects/arrays/unions/custom types)
+    max_depth: u32,
 }
 
 #[derive(Debug, Clone)]
@@ -41,7 +44,7 @@ struct FieldValidator {
 #[derive(Debug, Clone)]
 enum FieldType {
     String,
-    Number,
+    Number(NumericConstraint),
     Boolean,
     Array(Box<FieldType>),
     Object(HashMap<String, FieldValidator>),
@@ -60,6 +63,144 @@ enum FieldType {
     Union(Vec<FieldType>), // Add Union type for optimized union validation
 }
 
+// Numeric width/flavor for a `FieldType::Number`, borrowed from the
+// Int8/Int16/Int32/UInt32/Float32/Float64 vocabulary of columnar type
+// systems. `F64` is the bare "number" token: any finite value, integer
+// or not. `Int` is an unbounded integer ("int").
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum NumericKind {
+    F64,
+    Int,
+    Int8,
+    Int16,
+    Int32,
+    UInt32,
+    Float32,
+    Float64,
+}
+
+impl NumericKind {
+    fn name(&self) -> &'static str {
+        match self {
+            NumericKind::F64 => "number",
+            NumericKind::Int => "int",
+            NumericKind::Int8 => "int8",
+            NumericKind::Int16 => "int16",
+            NumericKind::Int32 => "int32",
+            NumericKind::UInt32 => "uint32",
+            NumericKind::Float32 => "float32",
+            NumericKind::Float64 => "float64",
+        }
+    }
+
+    fn is_integer(&self) -> bool {
+        matches!(self, NumericKind::Int | NumericKind::Int8 | NumericKind::Int16 | NumericKind::Int32 | NumericKind::UInt32)
+    }
+
+    // Representable range for the width, if the kind implies one.
+    fn range(&self) -> Option<(f64, f64)> {
+        match self {
+            NumericKind::Int8 => Some((i8::MIN as f64, i8::MAX as f64)),
+            NumericKind::Int16 => Some((i16::MIN as f64, i16::MAX as f64)),
+            NumericKind::Int32 => Some((i32::MIN as f64, i32::MAX as f64)),
+            NumericKind::UInt32 => Some((u32::MIN as f64, u32::MAX as f64)),
+            NumericKind::Float32 => Some((f32::MIN as f64, f32::MAX as f64)),
+            NumericKind::Int | NumericKind::F64 | NumericKind::Float64 => None,
+        }
+    }
+}
+
+// `FieldType::Number`'s payload: the numeric width plus an optional
+// `min`/`max` bound, e.g. from the `number(min=0,max=100)` DSL form.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct NumericConstraint {
+    kind: NumericKind,
+    min: Option<f64>,
+    max: Option<f64>,
+}
+
+impl Default for NumericConstraint {
+    fn default() -> Self {
+        NumericConstraint { kind: NumericKind::F64, min: None, max: None }
+    }
+}
+
+impl NumericConstraint {
+    fn unbounded(kind: NumericKind) -> Self {
+        NumericConstraint { kind, min: None, max: None }
+    }
+
+    fn type_name(&self) -> String {
+        match (self.min, self.max) {
+            (None, None) => self.kind.name().to_string(),
+            (min, max) => {
+                let mut parts = Vec::new();
+                if let Some(min) = min { parts.push(format!("min={}", min)); }
+                if let Some(max) = max { parts.push(format!("max={}", max)); }
+                format!("{}({})", self.kind.name(), parts.join(","))
+            }
+        }
+    }
+}
+
+// Finite check + integrality-for-width + min/max, shared by the scalar
+// validator and the SIMD number-array fast path.
+#[inline(always)]
+fn validate_numeric(value: &JsValue, constraint: &NumericConstraint) -> bool {
+    let Some(n) = value.as_f64() else { return false; };
+    if !n.is_finite() { return false; }
+    if constraint.kind.is_integer() && n.fract() != 0.0 { return false; }
+    if let Some((lo, hi)) = constraint.kind.range() {
+        if n < lo || n > hi { return false; }
+    }
+    if let Some(min) = constraint.min {
+        if n < min { return false; }
+    }
+    if let Some(max) = constraint.max {
+        if n > max { return false; }
+    }
+    true
+}
+
+// Maps a bare type-string token (no constraint suffix) to a NumericKind,
+// e.g. "int32" -> Int32. Returns None for non-numeric tokens.
+fn numeric_kind_from_name(name: &str) -> Option<NumericKind> {
+    match name {
+        "number" => Some(NumericKind::F64),
+        "int" => Some(NumericKind::Int),
+        "int8" => Some(NumericKind::Int8),
+        "int16" => Some(NumericKind::Int16),
+        "int32" => Some(NumericKind::Int32),
+        "uint32" => Some(NumericKind::UInt32),
+        "float" | "float32" => Some(NumericKind::Float32),
+        "float64" => Some(NumericKind::Float64),
+        _ => None,
+    }
+}
+
+// Parses the inside of a `(min=0,max=100)` constraint suffix.
+fn parse_numeric_constraints(params: &str) -> Result<(Option<f64>, Option<f64>), JsValue> {
+    let mut min = None;
+    let mut max = None;
+    for part in params.split(',') {
+        let part = part.trim();
+        if part.is_empty() { continue; }
+        let mut kv = part.splitn(2, '=');
+        let key = kv.next().unwrap_or("").trim();
+        let value = kv.next()
+            .ok_or_else(|| JsValue::from_str(&format!("Invalid numeric constraint: {}", part)))?
+            .trim();
+        let parsed: f64 = value.parse()
+            .map_err(|_| JsValue::from_str(&format!("Invalid numeric constraint value: {}", value)))?;
+        match key {
+            "min" => min = Some(parsed),
+            "max" => max = Some(parsed),
+            _ => return Err(JsValue::from_str(&format!("Unknown numeric constraint: {}", key))),
+        }
+    }
+    Ok((min, max))
+}
+
 // Union optimization cache
 #[derive(Debug, Clone)]
 struct UnionCache {
@@ -69,6 +210,121 @@ struct UnionCache {
     selectivity_order: Vec<usize>, // schemas ordered by selectivity (primitives first)
 }
 
+// A single actionable validation failure produced by `validate_collect`.
+// `path` is dotted for object fields and bracketed for array indices
+// (e.g. `address.zip`, `tags[3]`) so callers can point users at the
+// exact offending value.
+#[derive(Debug, Clone)]
+struct ValidationIssue {
+    path: String,
+    code: &'static str,
+    expected: String,
+    received: String,
+    // The actual offending value, rendered to a string. Only populated for
+    // `not_in_enum`, where `received` alone (the runtime type, e.g. "string")
+    // isn't enough to tell a caller *which* invalid value was passed.
+    value: Option<String>,
+}
+
+impl ValidationIssue {
+    fn new(path: &str, code: &'static str, expected: impl Into<String>, received: impl Into<String>) -> Self {
+        ValidationIssue {
+            path: path.to_string(),
+            code,
+            expected: expected.into(),
+            received: received.into(),
+            value: None,
+        }
+    }
+
+    fn with_value(path: &str, code: &'static str, expected: impl Into<String>, received: impl Into<String>, value: String) -> Self {
+        ValidationIssue {
+            path: path.to_string(),
+            code,
+            expected: expected.into(),
+            received: received.into(),
+            value: Some(value),
+        }
+    }
+
+    fn to_object(&self) -> Object {
+        let obj = Object::new();
+        let _ = Reflect::set(&obj, &JsValue::from_str("path"), &JsValue::from_str(&self.path));
+        let _ = Reflect::set(&obj, &JsValue::from_str("code"), &JsValue::from_str(self.code));
+        let _ = Reflect::set(&obj, &JsValue::from_str("expected"), &JsValue::from_str(&self.expected));
+        let _ = Reflect::set(&obj, &JsValue::from_str("received"), &JsValue::from_str(&self.received));
+        if let Some(value) = &self.value {
+            let _ = Reflect::set(&obj, &JsValue::from_str("value"), &JsValue::from_str(value));
+        }
+        obj
+    }
+}
+
+// Tracks recursion depth while walking a schema, so a deeply nested or
+// self-referential `Custom` type fails cleanly (once max_depth is exceeded)
+// instead of overflowing the WASM stack.
+struct RecursionState {
+    depth: u32,
+}
+
+impl RecursionState {
+    fn new() -> Self {
+        RecursionState { depth: 0 }
+    }
+}
+
+// A single (row, field) failure produced by `validate_columns`.
+#[derive(Debug, Clone)]
+struct ColumnFailure {
+    row: u32,
+    field: String,
+    code: &'static str,
+}
+
+impl ColumnFailure {
+    fn new(row: u32, field: &str, code: &'static str) -> Self {
+        ColumnFailure { row, field: field.to_string(), code }
+    }
+
+    fn to_object(&self) -> Object {
+        let obj = Object::new();
+        let _ = Reflect::set(&obj, &JsValue::from_str("row"), &JsValue::from_f64(self.row as f64));
+        let _ = Reflect::set(&obj, &JsValue::from_str("field"), &JsValue::from_str(&self.field));
+        let _ = Reflect::set(&obj, &JsValue::from_str("code"), &JsValue::from_str(self.code));
+        obj
+    }
+}
+
+// Runtime type name of a JS value, used for the `received` field of a ValidationIssue.
+fn js_type_name(value: &JsValue) -> &'static str {
+    if value.is_undefined() { "undefined" }
+    else if value.is_null() { "null" }
+    else if value.as_bool().is_some() { "boolean" }
+    else if value.as_f64().is_some() { "number" }
+    else if value.is_string() { "string" }
+    else if value.is_bigint() { "bigint" }
+    else if value.is_symbol() { "symbol" }
+    else if Array::is_array(value) { "array" }
+    else if value.is_function() { "function" }
+    else if value.is_object() { "object" }
+    else { "unknown" }
+}
+
+// Renders the actual value of a JS value to a string, for the `value` field
+// of a `not_in_enum` ValidationIssue. Falls back to JSON.stringify for
+// anything that isn't a primitive, and to the runtime type name if even
+// that fails (e.g. a value JSON can't represent, like a function).
+fn js_value_display(value: &JsValue) -> String {
+    if let Some(s) = value.as_string() { return s; }
+    if let Some(n) = value.as_f64() { return n.to_string(); }
+    if let Some(b) = value.as_bool() { return b.to_string(); }
+    if value.is_null() { return "null".to_string(); }
+    if value.is_undefined() { return "undefined".to_string(); }
+    JSON::stringify(value).ok()
+        .and_then(|s| s.as_string())
+        .unwrap_or_else(|| js_type_name(value).to_string())
+}
+
 #[wasm_bindgen]
 impl DhiCore {
     #[wasm_bindgen(constructor)]
@@ -86,6 +342,7 @@ impl DhiCore {
             fast_fields: Vec::new(),
             union_caches: HashMap::new(),
             presence_bitmap_cache: HashMap::new(),
+            max_depth: DEFAULT_MAX_DEPTH,
         }
     }
 
@@ -94,6 +351,16 @@ impl DhiCore {
         self.batch_size
     }
 
+    #[wasm_bindgen]
+    pub fn get_max_depth(&self) -> u32 {
+        self.max_depth
+    }
+
+    #[wasm_bindgen]
+    pub fn set_max_depth(&mut self, depth: u32) {
+        self.max_depth = depth;
+    }
+
     #[wasm_bindgen]
     pub fn set_batch_size(&mut self, size: i32) {
         self.batch_size = size;
@@ -192,9 +459,158 @@ impl DhiCore {
         Ok(())
     }
 
+    // Load a schema from a parsed Avro record document (caller does JSON.parse first).
+    // Replaces the root schema; named records along the way are registered in
+    // `custom_types` so later `$ref`-style reuse resolves through FieldType::Custom.
+    #[wasm_bindgen]
+    pub fn load_avro_schema(&mut self, avro_schema: JsValue) -> Result<(), JsValue> {
+        let root = self.avro_record_to_fields(&avro_schema, 0)?;
+        self.schema = root;
+        self.invalidate_cache();
+        Ok(())
+    }
+
+    // Converts an Avro `record` schema object's `fields` array into our FieldValidator map.
+    // If the record is named, it is registered in `custom_types` before its fields are
+    // parsed, so a record can reference itself recursively. `depth` bounds nesting the
+    // same way `parse_field_type_at_depth` does, so a deeply nested or adversarial Avro
+    // document can't overflow the WASM stack while the schema is still being built.
+    fn avro_record_to_fields(&mut self, record: &JsValue, depth: u32) -> Result<HashMap<String, FieldValidator>, JsValue> {
+        if depth > self.max_depth {
+            return Err(JsValue::from_str("Avro schema nested too deeply"));
+        }
+
+        let record_obj = record.dyn_ref::<Object>()
+            .ok_or_else(|| JsValue::from_str("Avro record must be an object"))?;
+
+        let name = Reflect::get(record_obj, &JsValue::from_str("name")).ok().and_then(|v| v.as_string());
+        if let Some(name) = &name {
+            self.custom_types.entry(name.clone()).or_default();
+        }
+
+        let fields_val = Reflect::get(record_obj, &JsValue::from_str("fields"))
+            .map_err(|_| JsValue::from_str("Avro record missing 'fields'"))?;
+        let fields_array = fields_val.dyn_ref::<Array>()
+            .ok_or_else(|| JsValue::from_str("Avro 'fields' must be an array"))?;
+
+        let mut fields = HashMap::new();
+        for i in 0..fields_array.length() {
+            let field_val = fields_array.get(i);
+            let field_obj = field_val.dyn_ref::<Object>()
+                .ok_or_else(|| JsValue::from_str("Avro field must be an object"))?;
+
+            let field_name = Reflect::get(field_obj, &JsValue::from_str("name")).ok()
+                .and_then(|v| v.as_string())
+                .ok_or_else(|| JsValue::from_str("Avro field missing 'name'"))?;
+            let type_val = Reflect::get(field_obj, &JsValue::from_str("type"))
+                .map_err(|_| JsValue::from_str("Avro field missing 'type'"))?;
+
+            let (field_type, required) = self.avro_type_to_field_type(&type_val, depth + 1)?;
+            let key = JsValue::from_str(&field_name);
+            fields.insert(field_name, FieldValidator { field_type, required, key });
+        }
+
+        if let Some(name) = name {
+            self.custom_types.insert(name, fields.clone());
+        }
+
+        Ok(fields)
+    }
+
+    // Converts a single Avro type (primitive name, union array, or complex type object)
+    // into our FieldType, plus whether the field is required (a union with a "null"
+    // branch is treated as optional, matching Avro's "nullable field" idiom).
+    fn avro_type_to_field_type(&mut self, type_val: &JsValue, depth: u32) -> Result<(FieldType, bool), JsValue> {
+        if depth > self.max_depth {
+            return Err(JsValue::from_str("Avro schema nested too deeply"));
+        }
+
+        if let Some(name) = type_val.as_string() {
+            return Ok((self.avro_named_type_to_field_type(&name)?, true));
+        }
+
+        if let Some(union_branches) = type_val.dyn_ref::<Array>() {
+            let mut required = true;
+            let mut has_null = false;
+            let mut branches = Vec::new();
+            for i in 0..union_branches.length() {
+                let branch = union_branches.get(i);
+                if branch.as_string().as_deref() == Some("null") {
+                    // Avro's "nullable field" idiom: the field may also be omitted
+                    // entirely, but an explicit JSON null still needs its own branch
+                    // below (required=false alone only permits undefined).
+                    required = false;
+                    has_null = true;
+                    continue;
+                }
+                let (branch_type, _) = self.avro_type_to_field_type(&branch, depth + 1)?;
+                branches.push(branch_type);
+            }
+            if has_null {
+                branches.push(FieldType::Null);
+            }
+            let field_type = match branches.len() {
+                1 => branches.into_iter().next().unwrap(),
+                _ => FieldType::Union(branches),
+            };
+            return Ok((field_type, required));
+        }
+
+        let complex_obj = type_val.dyn_ref::<Object>()
+            .ok_or_else(|| JsValue::from_str("Unsupported Avro type"))?;
+        let kind = Reflect::get(complex_obj, &JsValue::from_str("type")).ok()
+            .and_then(|v| v.as_string())
+            .ok_or_else(|| JsValue::from_str("Avro complex type missing 'type'"))?;
+
+        let field_type = match kind.as_str() {
+            "record" => FieldType::Object(self.avro_record_to_fields(type_val, depth + 1)?),
+            "array" => {
+                let items_val = Reflect::get(complex_obj, &JsValue::from_str("items"))
+                    .map_err(|_| JsValue::from_str("Avro array missing 'items'"))?;
+                let (item_type, _) = self.avro_type_to_field_type(&items_val, depth + 1)?;
+                FieldType::Array(Box::new(item_type))
+            }
+            "map" => {
+                let values_val = Reflect::get(complex_obj, &JsValue::from_str("values"))
+                    .map_err(|_| JsValue::from_str("Avro map missing 'values'"))?;
+                let (value_type, _) = self.avro_type_to_field_type(&values_val, depth + 1)?;
+                FieldType::Record(Box::new(value_type))
+            }
+            "enum" => {
+                let symbols_val = Reflect::get(complex_obj, &JsValue::from_str("symbols"))
+                    .map_err(|_| JsValue::from_str("Avro enum missing 'symbols'"))?;
+                let symbols_array = symbols_val.dyn_ref::<Array>()
+                    .ok_or_else(|| JsValue::from_str("Avro enum 'symbols' must be an array"))?;
+                let symbols = (0..symbols_array.length())
+                    .filter_map(|i| symbols_array.get(i).as_string())
+                    .collect();
+                FieldType::Enum(symbols)
+            }
+            other => self.avro_named_type_to_field_type(other)?,
+        };
+        Ok((field_type, true))
+    }
+
+    // Maps an Avro primitive name to FieldType, falling back to a Custom reference
+    // for a previously-defined named record (Avro's `$ref`-style reuse by name).
+    fn avro_named_type_to_field_type(&self, name: &str) -> Result<FieldType, JsValue> {
+        match name {
+            "null" => Ok(FieldType::Null),
+            "boolean" => Ok(FieldType::Boolean),
+            "int" => Ok(FieldType::Number(NumericConstraint::unbounded(NumericKind::Int32))),
+            "long" => Ok(FieldType::Number(NumericConstraint::unbounded(NumericKind::Int))),
+            "float" => Ok(FieldType::Number(NumericConstraint::unbounded(NumericKind::Float32))),
+            "double" => Ok(FieldType::Number(NumericConstraint::unbounded(NumericKind::Float64))),
+            "bytes" | "string" => Ok(FieldType::String),
+            _ if self.custom_types.contains_key(name) => Ok(FieldType::Custom(name.to_string())),
+            _ => Err(JsValue::from_str(&format!("Unsupported Avro type: {}", name))),
+        }
+    }
+
     #[wasm_bindgen]
     pub fn validate(&self, value: JsValue) -> Result<bool, JsValue> {
-        Ok(self.validate_value_internal(&value))
+        let mut state = RecursionState::new();
+        Ok(self.validate_value_internal(&value, &mut state))
     }
 
     fn invalidate_cache(&mut self) {
@@ -213,9 +629,13 @@ impl DhiCore {
             matches!(v.field_type, FieldType::Object(_))
         });
         
-        self.is_strict_primitive_schema = !self.has_complex_types && 
-            self.schema.values().all(|v| v.required && 
-                matches!(v.field_type, FieldType::String | FieldType::Number | FieldType::Boolean));
+        self.is_strict_primitive_schema = !self.has_complex_types &&
+            self.schema.values().all(|v| {
+                v.required && (
+                    matches!(v.field_type, FieldType::String | FieldType::Boolean)
+                    || matches!(&v.field_type, FieldType::Number(c) if *c == NumericConstraint::default())
+                )
+            });
         
         // Rebuild flattened data structures
         if self.is_strict_primitive_schema {
@@ -223,7 +643,7 @@ impl DhiCore {
                 .map(|(_k, v)| {
                     let tag = match v.field_type {
                         FieldType::String => 0u8,
-                        FieldType::Number => 1u8,
+                        FieldType::Number(_) => 1u8,
                         FieldType::Boolean => 2u8,
                         _ => 255u8,
                     };
@@ -251,6 +671,158 @@ impl DhiCore {
         }
     }
 
+    // Struct-of-arrays batch validation: `columns` is a JS object mapping
+    // each field name to its own column Array (all columns the same
+    // length). Each column is type-checked in its own tight batched loop
+    // (the same shape as `validate_string_array_simd` et al.) instead of
+    // paying per-row `Reflect::get` overhead on row objects.
+    // Required-field presence is checked via the same presence-bitmap
+    // technique as `validate_asymmetric_fast`: a per-row bitmap lets rows
+    // with every required field present skip straight to type-checking,
+    // and only a failing row pays for a field-by-field scan to find which
+    // ones were actually missing. Returns every (row, field) failure
+    // rather than a single pass/fail, so a caller can filter or reject
+    // just the bad rows.
+    #[wasm_bindgen]
+    pub fn validate_columns(&self, columns: JsValue) -> Result<Array, JsValue> {
+        let Some(columns_obj) = columns.dyn_ref::<Object>() else {
+            return Err(JsValue::from_str("validate_columns expects an object of field name to column array"));
+        };
+
+        // Resolve each field's column once; `None` stands for a wholly
+        // missing column the same way an out-of-range row does.
+        let fields: Vec<(&String, &FieldValidator, Option<Array>)> = self.schema.iter()
+            .map(|(name, validator)| {
+                let column = Reflect::get(columns_obj, &validator.key).ok()
+                    .and_then(|v| v.dyn_ref::<Array>().cloned());
+                (name, validator, column)
+            })
+            .collect();
+
+        // Row count is the length of the longest column present; fields
+        // shorter than that (or missing entirely) are "missing" for the
+        // trailing rows.
+        let rows = fields.iter()
+            .filter_map(|(_, _, column)| column.as_ref().map(|c| c.length() as usize))
+            .max()
+            .unwrap_or(0);
+
+        let required_mask: u32 = fields.iter()
+            .filter(|(_, validator, _)| validator.required)
+            .filter_map(|(name, _, _)| self.presence_bitmap_cache.get(*name))
+            .fold(0, |mask, bit| mask | bit);
+
+        let is_present = |column: &Option<Array>, row: usize| -> bool {
+            column.as_ref().is_some_and(|c| row < c.length() as usize && !c.get(row as u32).is_undefined())
+        };
+
+        // Schemas with more than 32 fields overflow the bitmap (same cap as
+        // `validate_asymmetric_fast`); anything past it is checked directly
+        // every row instead of folding into the fast mask.
+        let unbitmapped_required: Vec<_> = fields.iter()
+            .filter(|(name, validator, _)| validator.required && !self.presence_bitmap_cache.contains_key(*name))
+            .collect();
+
+        let mut failures = Vec::new();
+
+        // Presence pass: row-at-a-time bitmap check, falling back to a
+        // field scan only for rows actually missing something.
+        for row in 0..rows {
+            let mut present_mask = 0u32;
+            for (name, _, column) in &fields {
+                if let Some(bit) = self.presence_bitmap_cache.get(*name) {
+                    if is_present(column, row) { present_mask |= bit; }
+                }
+            }
+            if (present_mask & required_mask) != required_mask {
+                for (name, validator, column) in &fields {
+                    if validator.required && self.presence_bitmap_cache.contains_key(*name) && !is_present(column, row) {
+                        failures.push(ColumnFailure::new(row as u32, name.as_str(), "missing"));
+                    }
+                }
+            }
+            for (name, _, column) in &unbitmapped_required {
+                if !is_present(column, row) {
+                    failures.push(ColumnFailure::new(row as u32, name.as_str(), "missing"));
+                }
+            }
+        }
+
+        // Type pass: column-major, in typed batch loops shaped like the SIMD ones;
+        // missing entries are already accounted for above.
+        for (field_name, validator, column) in &fields {
+            let Some(column) = column else { continue; };
+            match &validator.field_type {
+                FieldType::String => self.validate_string_column(column, rows, field_name.as_str(), &mut failures),
+                FieldType::Number(constraint) => self.validate_number_column(column, rows, field_name.as_str(), constraint, &mut failures),
+                FieldType::Boolean => self.validate_boolean_column(column, rows, field_name.as_str(), &mut failures),
+                other => self.validate_generic_column(column, rows, field_name.as_str(), other, &mut failures),
+            }
+        }
+
+        let results = Array::new_with_length(failures.len() as u32);
+        for (i, failure) in failures.iter().enumerate() {
+            results.set(i as u32, failure.to_object().into());
+        }
+        Ok(results)
+    }
+
+    // Column validators below mirror validate_string_array_simd et al. but
+    // record every failing row instead of short-circuiting on the first
+    // one. Missing values are the presence pass's job, not theirs.
+
+    fn validate_string_column(&self, column: &Array, rows: usize, field_name: &str, failures: &mut Vec<ColumnFailure>) {
+        for batch_start in (0..rows).step_by(SIMD_BATCH_SIZE) {
+            let batch_end = (batch_start + SIMD_BATCH_SIZE).min(rows);
+            for row in batch_start..batch_end {
+                if row >= column.length() as usize { continue; }
+                let item = column.get(row as u32);
+                if !item.is_undefined() && !item.is_string() {
+                    failures.push(ColumnFailure::new(row as u32, field_name, "type_mismatch"));
+                }
+            }
+        }
+    }
+
+    fn validate_number_column(&self, column: &Array, rows: usize, field_name: &str, constraint: &NumericConstraint, failures: &mut Vec<ColumnFailure>) {
+        for batch_start in (0..rows).step_by(SIMD_BATCH_SIZE) {
+            let batch_end = (batch_start + SIMD_BATCH_SIZE).min(rows);
+            for row in batch_start..batch_end {
+                if row >= column.length() as usize { continue; }
+                let item = column.get(row as u32);
+                if !item.is_undefined() && !validate_numeric(&item, constraint) {
+                    failures.push(ColumnFailure::new(row as u32, field_name, "type_mismatch"));
+                }
+            }
+        }
+    }
+
+    fn validate_boolean_column(&self, column: &Array, rows: usize, field_name: &str, failures: &mut Vec<ColumnFailure>) {
+        for batch_start in (0..rows).step_by(SIMD_BATCH_SIZE) {
+            let batch_end = (batch_start + SIMD_BATCH_SIZE).min(rows);
+            for row in batch_start..batch_end {
+                if row >= column.length() as usize { continue; }
+                let item = column.get(row as u32);
+                if !item.is_undefined() && item.as_bool().is_none() {
+                    failures.push(ColumnFailure::new(row as u32, field_name, "type_mismatch"));
+                }
+            }
+        }
+    }
+
+    // Fallback for nested/complex column types (Object, Array, Union, Custom, ...).
+    fn validate_generic_column(&self, column: &Array, rows: usize, field_name: &str, field_type: &FieldType, failures: &mut Vec<ColumnFailure>) {
+        for row in 0..rows {
+            if row >= column.length() as usize { continue; }
+            let item = column.get(row as u32);
+            if item.is_undefined() { continue; }
+            let mut state = RecursionState::new();
+            if !self.validate_value_bool(&item, field_type, &mut state) {
+                failures.push(ColumnFailure::new(row as u32, field_name, "type_mismatch"));
+            }
+        }
+    }
+
     #[wasm_bindgen]
     pub fn validate_batch(&self, items: Array) -> Result<Array, JsValue> {
         let len = items.length() as usize;
@@ -305,15 +877,10 @@ impl DhiCore {
         for (i, obj_val) in objects.iter().enumerate() {
             let valid = if let Some(obj) = obj_val.dyn_ref::<Object>() {
                 if let Ok(value) = Reflect::get(obj, field_key) {
-                    !value.is_undefined() && match field_tag {
-                        0 => value.is_string(),
-                        1 => value.as_f64().is_some(),
-                        2 => value.as_bool().is_some(),
-                        _ => false,
-                    }
+                    !value.is_undefined() && self.validate_primitive_type(&value, *field_tag)
                 } else { false }
             } else { false };
-            
+
             results.set((offset + i) as u32, JsValue::from_bool(valid));
         }
     }
@@ -422,7 +989,8 @@ impl DhiCore {
 
                 let valid = self.fast_fields.iter().all(|(field_name, field_type)| {
                     if let Ok(value) = Reflect::get(obj, field_name) {
-                        self.validate_value_bool(&value, field_type)
+                        let mut state = RecursionState::new();
+                        self.validate_value_bool(&value, field_type, &mut state)
                     } else { false }
                 });
                 
@@ -472,7 +1040,8 @@ impl DhiCore {
             
             for i in batch_start..batch_end {
                 let item = items.get(i as u32);
-                let is_valid = self.validate_asymmetric_fast(&item);
+                let mut state = RecursionState::new();
+                let is_valid = self.validate_asymmetric_fast(&item, &mut state);
                 results.set(i as u32, JsValue::from_bool(is_valid));
             }
         }
@@ -603,80 +1172,80 @@ impl DhiCore {
     
     // Fast asymmetric structure validation with presence bitmaps
     #[inline(always)]
-    fn validate_asymmetric_fast(&self, value: &JsValue) -> bool {
+    fn validate_asymmetric_fast(&self, value: &JsValue, state: &mut RecursionState) -> bool {
         if !value.is_object() { return false; }
         let Some(obj) = value.dyn_ref::<Object>() else { return false; };
-        
+
         let mut present_mask = 0u32;
         let mut required_mask = 0u32;
-        
+
         // Build presence and required masks in single pass
         for (i, (_field_name, validator)) in self.schema.iter().enumerate() {
             if i >= 32 { break; } // Limit to 32 fields for bitmap
-            
+
             let bit = 1u32 << i;
             if validator.required {
                 required_mask |= bit;
             }
-            
+
             let Ok(field_value) = Reflect::get(obj, &validator.key) else { continue; };
-            
+
             if !field_value.is_undefined() {
                 present_mask |= bit;
-                
+
                 // Validate field value
-                if !self.validate_value_bool(&field_value, &validator.field_type) {
+                if !self.validate_value_bool(&field_value, &validator.field_type, state) {
                     return false;
                 }
             }
         }
-        
+
         // Check required fields using bitmap operation
         (present_mask & required_mask) == required_mask
     }
-    
+
     // Helper: validate remaining non-union fields
     #[inline(always)]
-    fn validate_remaining_fields(&self, obj: &Object, skip_field: &str) -> bool {
+    fn validate_remaining_fields(&self, obj: &Object, skip_field: &str, state: &mut RecursionState) -> bool {
         for (field_name, validator) in &self.schema {
             if field_name == skip_field { continue; }
-            
+
             let Ok(field_value) = Reflect::get(obj, &validator.key) else {
                 if validator.required { return false; }
                 continue;
             };
-            
+
             if validator.required && field_value.is_undefined() {
                 return false;
             }
-            
+
             if !field_value.is_undefined() {
-                if !self.validate_value_bool(&field_value, &validator.field_type) {
+                if !self.validate_value_bool(&field_value, &validator.field_type, state) {
                     return false;
                 }
             }
         }
         true
     }
-    
+
     // Monomorphic array validation
     #[inline(always)]
-    fn validate_array_monomorphic(&self, value: &JsValue, item_type: &FieldType) -> bool {
+    fn validate_array_monomorphic(&self, value: &JsValue, item_type: &FieldType, state: &mut RecursionState) -> bool {
         let Some(array) = value.dyn_ref::<Array>() else { return false; };
-        
+
         // Use specialized SIMD validation based on item type
         match item_type {
             FieldType::String => self.validate_string_array_simd(array, array.length() as usize),
-            FieldType::Number => self.validate_number_array_simd(array, array.length() as usize),
+            FieldType::Number(constraint) => self.validate_number_array_simd(array, array.length() as usize, constraint),
             FieldType::Boolean => self.validate_boolean_array_simd(array, array.length() as usize),
-            _ => self.validate_array_optimized(array, item_type)
+            _ => self.validate_array_optimized(array, item_type, state)
         }
     }
-    
+
     // Monomorphic object validation
     #[inline(always)]
-    fn validate_object_monomorphic(&self, value: &JsValue, schema: &HashMap<String, FieldValidator>) -> bool {
-        self.validate_object_bool(value, schema)
+    fn validate_object_monomorphic(&self, value: &JsValue, schema: &HashMap<String, FieldValidator>, state: &mut RecursionState) -> bool {
+        self.validate_object_bool(value, schema, state)
     }
 
     // Inline primitive type validation for better performance
@@ -684,21 +1253,21 @@ impl DhiCore {
     fn validate_primitive_type(&self, value: &JsValue, tag: u8) -> bool {
         match tag {
             0 => value.is_string(),
-            1 => value.as_f64().is_some(),
+            1 => value.as_f64().is_some_and(|n| n.is_finite()),
             2 => value.as_bool().is_some(),
             _ => false,
         }
     }
 
     // Optimized array validation with vectorized processing
-    fn validate_array_optimized(&self, array: &Array, item_type: &FieldType) -> bool {
+    fn validate_array_optimized(&self, array: &Array, item_type: &FieldType, state: &mut RecursionState) -> bool {
         let len = array.length() as usize;
         if len == 0 { return true; }
-        
+
         // For primitive arrays, use SIMD-style validation
         match item_type {
             FieldType::String => self.validate_string_array_simd(array, len),
-            FieldType::Number => self.validate_number_array_simd(array, len),
+            FieldType::Number(constraint) => self.validate_number_array_simd(array, len, constraint),
             FieldType::Boolean => self.validate_boolean_array_simd(array, len),
             _ => {
                 // Fallback to chunked validation for complex types
@@ -706,7 +1275,7 @@ impl DhiCore {
                     let chunk_end = (chunk_start + SIMD_BATCH_SIZE).min(len);
                     for i in chunk_start..chunk_end {
                         let item = array.get(i as u32);
-                        if !self.validate_value_bool(&item, item_type) {
+                        if !self.validate_value_bool(&item, item_type, state) {
                             return false;
                         }
                     }
@@ -733,15 +1302,17 @@ impl DhiCore {
         true
     }
 
-    // SIMD-style number array validation
+    // SIMD-style number array validation. Applies the same finite/integral/
+    // range check as the scalar path so monomorphic numeric arrays keep
+    // their tight typed loop while still honoring int widths and min/max.
     #[inline(always)]
-    fn validate_number_array_simd(&self, array: &Array, len: usize) -> bool {
+    fn validate_number_array_simd(&self, array: &Array, len: usize, constraint: &NumericConstraint) -> bool {
         for batch_start in (0..len).step_by(SIMD_BATCH_SIZE) {
             let batch_end = (batch_start + SIMD_BATCH_SIZE).min(len);
-            
+
             for i in batch_start..batch_end {
                 let item = array.get(i as u32);
-                if item.as_f64().is_none() {
+                if !validate_numeric(&item, constraint) {
                     return false;
                 }
             }
@@ -770,7 +1341,7 @@ impl DhiCore {
         self.debug = debug;
     }
 
-    fn validate_value_internal(&self, value: &JsValue) -> bool {
+    fn validate_value_internal(&self, value: &JsValue, state: &mut RecursionState) -> bool {
         if !value.is_object() {
             return false;
         }
@@ -790,7 +1361,7 @@ impl DhiCore {
                         return false;
                     }
                     if !nested_value.is_undefined() {
-                        if !self.validate_object_bool(&nested_value, nested_schema) {
+                        if !self.validate_object_bool(&nested_value, nested_schema, state) {
                             return false;
                         }
                     }
@@ -804,7 +1375,7 @@ impl DhiCore {
                         return false;
                     }
                     if !field_value.is_undefined() {
-                        if !self.validate_value_bool(&field_value, &validator.field_type) {
+                        if !self.validate_value_bool(&field_value, &validator.field_type, state) {
                             return false;
                         }
                     }
@@ -814,39 +1385,230 @@ impl DhiCore {
         true
     }
 
+    // Like `validate`, but instead of collapsing to a bare bool this walks the
+    // whole schema and accumulates every issue it finds: missing required
+    // fields (all of them, not just the first), type mismatches, enum misses,
+    // and union branches that all failed. Returns an empty array when valid.
+    #[wasm_bindgen]
+    pub fn validate_collect(&self, value: JsValue) -> Result<Array, JsValue> {
+        let mut issues = Vec::new();
+        let mut state = RecursionState::new();
+        self.validate_collect_object(&value, &self.schema, "", &mut issues, &mut state);
+
+        let results = Array::new_with_length(issues.len() as u32);
+        for (i, issue) in issues.iter().enumerate() {
+            results.set(i as u32, issue.to_object().into());
+        }
+        Ok(results)
+    }
+
+    fn validate_collect_object(
+        &self,
+        value: &JsValue,
+        schema: &HashMap<String, FieldValidator>,
+        path: &str,
+        issues: &mut Vec<ValidationIssue>,
+        state: &mut RecursionState,
+    ) {
+        if state.depth > self.max_depth {
+            issues.push(ValidationIssue::new(path, "max_depth_exceeded", "", js_type_name(value)));
+            return;
+        }
+
+        let Some(obj) = value.dyn_ref::<Object>() else {
+            issues.push(ValidationIssue::new(path, "type_mismatch", "object", js_type_name(value)));
+            return;
+        };
+
+        state.depth += 1;
+        for (field_name, validator) in schema {
+            let field_path = if path.is_empty() {
+                field_name.clone()
+            } else {
+                format!("{}.{}", path, field_name)
+            };
+
+            let field_value = Reflect::get(obj, &validator.key).unwrap_or(JsValue::UNDEFINED);
+            if field_value.is_undefined() {
+                if validator.required {
+                    issues.push(ValidationIssue::new(
+                        &field_path,
+                        "missing",
+                        self.field_type_name(&validator.field_type),
+                        "undefined",
+                    ));
+                }
+                continue;
+            }
+
+            self.validate_collect_value(&field_value, &validator.field_type, &field_path, issues, state);
+        }
+        state.depth -= 1;
+    }
+
+    fn validate_collect_value(
+        &self,
+        value: &JsValue,
+        field_type: &FieldType,
+        path: &str,
+        issues: &mut Vec<ValidationIssue>,
+        state: &mut RecursionState,
+    ) {
+        match field_type {
+            FieldType::Object(nested_schema) => {
+                self.validate_collect_object(value, nested_schema, path, issues, state);
+            }
+            FieldType::Custom(type_name) => {
+                if let Some(custom_schema) = self.custom_types.get(type_name) {
+                    // validate_collect_object bumps state.depth itself, so a
+                    // self-referential custom type still fails cleanly past max_depth.
+                    self.validate_collect_object(value, custom_schema, path, issues, state);
+                }
+            }
+            FieldType::Array(item_type) => {
+                if state.depth > self.max_depth {
+                    issues.push(ValidationIssue::new(path, "max_depth_exceeded", "", js_type_name(value)));
+                    return;
+                }
+                let Some(array) = value.dyn_ref::<Array>() else {
+                    issues.push(ValidationIssue::new(path, "type_mismatch", "array", js_type_name(value)));
+                    return;
+                };
+                state.depth += 1;
+                for i in 0..array.length() {
+                    let item = array.get(i);
+                    let item_path = format!("{}[{}]", path, i);
+                    self.validate_collect_value(&item, item_type, &item_path, issues, state);
+                }
+                state.depth -= 1;
+            }
+            FieldType::Record(value_type) => {
+                if state.depth > self.max_depth {
+                    issues.push(ValidationIssue::new(path, "max_depth_exceeded", "", js_type_name(value)));
+                    return;
+                }
+                let Some(obj) = value.dyn_ref::<Object>() else {
+                    issues.push(ValidationIssue::new(path, "type_mismatch", "object", js_type_name(value)));
+                    return;
+                };
+                state.depth += 1;
+                let keys = Object::keys(obj);
+                let values = Object::values(obj);
+                for i in 0..values.length() {
+                    let v = values.get(i);
+                    let key = keys.get(i).as_string().unwrap_or_default();
+                    let entry_path = if path.is_empty() { key.clone() } else { format!("{}.{}", path, key) };
+                    self.validate_collect_value(&v, value_type, &entry_path, issues, state);
+                }
+                state.depth -= 1;
+            }
+            FieldType::Enum(allowed_values) => {
+                let Some(str_val) = value.as_string() else {
+                    issues.push(ValidationIssue::with_value(path, "not_in_enum", allowed_values.join(","), js_type_name(value), js_value_display(value)));
+                    return;
+                };
+                if !allowed_values.contains(&str_val) {
+                    issues.push(ValidationIssue::with_value(path, "not_in_enum", allowed_values.join(","), js_type_name(value), str_val));
+                }
+            }
+            FieldType::Union(union_types) => {
+                if !union_types.iter().any(|t| self.validate_value_bool(value, t, state)) {
+                    let attempted: Vec<String> = union_types.iter().map(|t| self.field_type_name(t)).collect();
+                    issues.push(ValidationIssue::new(path, "union_no_match", attempted.join("|"), js_type_name(value)));
+                }
+            }
+            _ => {
+                if !self.validate_value_bool(value, field_type, state) {
+                    issues.push(ValidationIssue::new(path, "type_mismatch", self.field_type_name(field_type), js_type_name(value)));
+                }
+            }
+        }
+    }
+
+    // Human-readable name for a FieldType, used as the `expected` field of a ValidationIssue.
+    fn field_type_name(&self, field_type: &FieldType) -> String {
+        match field_type {
+            FieldType::String => "string".to_string(),
+            FieldType::Number(constraint) => constraint.type_name(),
+            FieldType::Boolean => "boolean".to_string(),
+            FieldType::Array(inner) => format!("Array<{}>", self.field_type_name(inner)),
+            FieldType::Object(_) => "object".to_string(),
+            FieldType::Custom(type_name) => type_name.clone(),
+            FieldType::Any => "any".to_string(),
+            FieldType::Record(inner) => format!("Record<{}>", self.field_type_name(inner)),
+            FieldType::Date => "date".to_string(),
+            FieldType::BigInt => "bigint".to_string(),
+            FieldType::Symbol => "symbol".to_string(),
+            FieldType::Undefined => "undefined".to_string(),
+            FieldType::Null => "null".to_string(),
+            FieldType::Void => "void".to_string(),
+            FieldType::Unknown => "unknown".to_string(),
+            FieldType::Never => "never".to_string(),
+            FieldType::Enum(values) => format!("enum:{}", values.join(",")),
+            FieldType::Union(types) => format!(
+                "Union<{}>",
+                types.iter().map(|t| self.field_type_name(t)).collect::<Vec<_>>().join(",")
+            ),
+        }
+    }
+
     fn validate_object(&self, value: &JsValue, schema: &HashMap<String, FieldValidator>) -> Result<(), JsValue> {
-        if self.validate_object_bool(value, schema) {
+        let mut state = RecursionState::new();
+        if self.validate_object_bool(value, schema, &mut state) {
             Ok(())
         } else {
             Err(JsValue::from_bool(false))
         }
     }
 
-    fn validate_object_bool(&self, value: &JsValue, schema: &HashMap<String, FieldValidator>) -> bool {
+    fn validate_object_bool(&self, value: &JsValue, schema: &HashMap<String, FieldValidator>, state: &mut RecursionState) -> bool {
+        if state.depth > self.max_depth {
+            return false;
+        }
+
         let Some(obj) = value.dyn_ref::<Object>() else {
             return false;
         };
 
+        state.depth += 1;
         for (_field_name, validator) in schema {
             let Ok(field_value) = Reflect::get(obj, &validator.key) else {
+                state.depth -= 1;
                 return false;
             };
             if validator.required && field_value.is_undefined() {
+                state.depth -= 1;
                 return false;
             }
             if !field_value.is_undefined() {
-                if !self.validate_value_bool(&field_value, &validator.field_type) {
+                if !self.validate_value_bool(&field_value, &validator.field_type, state) {
+                    state.depth -= 1;
                     return false;
                 }
             }
         }
+        state.depth -= 1;
         true
     }
 
     fn parse_field_type(&self, field_type: &str) -> Result<FieldType, JsValue> {
+        self.parse_field_type_at_depth(field_type, 0)
+    }
+
+    // Recurses through Array<...>, Record<...>, and Union<...>. Depth-limited
+    // the same way as validate_value_bool, so a pathologically nested type
+    // string can't blow the stack while parsing a schema.
+    fn parse_field_type_at_depth(&self, field_type: &str, depth: u32) -> Result<FieldType, JsValue> {
+        if depth > self.max_depth {
+            return Err(JsValue::from_str("Type definition nested too deeply"));
+        }
+
+        if let Some(kind) = numeric_kind_from_name(field_type) {
+            return Ok(FieldType::Number(NumericConstraint::unbounded(kind)));
+        }
+
         match field_type {
             "string" => Ok(FieldType::String),
-            "number" => Ok(FieldType::Number),
             "boolean" => Ok(FieldType::Boolean),
             "object" => Ok(FieldType::Object(HashMap::new())),
             "record" => Ok(FieldType::Record(Box::new(FieldType::Any))),
@@ -865,21 +1627,32 @@ impl DhiCore {
                     ));
                 }
                 if let Some(inner_type) = field_type.strip_prefix("Array<").and_then(|s| s.strip_suffix(">")) {
-                    let inner = self.parse_field_type(inner_type)?;
+                    let inner = self.parse_field_type_at_depth(inner_type, depth + 1)?;
                     return Ok(FieldType::Array(Box::new(inner)));
                 }
                 if let Some(inner_type) = field_type.strip_prefix("Record<").and_then(|s| s.strip_suffix(">")) {
-                    let inner = self.parse_field_type(inner_type)?;
+                    let inner = self.parse_field_type_at_depth(inner_type, depth + 1)?;
                     return Ok(FieldType::Record(Box::new(inner)));
                 }
                 if let Some(union_types) = field_type.strip_prefix("Union<").and_then(|s| s.strip_suffix(">")) {
                     let type_parts: Vec<&str> = union_types.split(',').map(|s| s.trim()).collect();
                     let mut union_field_types = Vec::new();
                     for type_part in type_parts {
-                        union_field_types.push(self.parse_field_type(type_part)?);
+                        union_field_types.push(self.parse_field_type_at_depth(type_part, depth + 1)?);
                     }
                     return Ok(FieldType::Union(union_field_types));
                 }
+                // Constraint form, e.g. "number(min=0,max=100)" or "int32(min=0)"
+                if let Some(paren_start) = field_type.find('(') {
+                    if let Some(params) = field_type.strip_suffix(')') {
+                        let name = &field_type[..paren_start];
+                        let params = &params[paren_start + 1..];
+                        if let Some(kind) = numeric_kind_from_name(name) {
+                            let (min, max) = parse_numeric_constraints(params)?;
+                            return Ok(FieldType::Number(NumericConstraint { kind, min, max }));
+                        }
+                    }
+                }
                 if self.custom_types.contains_key(field_type) {
                     return Ok(FieldType::Custom(field_type.to_string()));
                 }
@@ -891,32 +1664,46 @@ impl DhiCore {
     // Add back validate_value method
     #[inline(always)]
     fn validate_value(&self, value: &JsValue, field_type: &FieldType) -> Result<(), JsValue> {
-        if self.validate_value_bool(value, field_type) {
+        let mut state = RecursionState::new();
+        if self.validate_value_bool(value, field_type, &mut state) {
             Ok(())
         } else {
             Err(JsValue::from_bool(false))
         }
     }
 
+    // Recurses through Array/Object/Record/Union/Custom. `state` tracks the
+    // current depth so a deeply nested or self-referential schema fails
+    // gracefully once max_depth is exceeded, instead of overflowing the WASM
+    // stack (see `set_max_depth`).
     #[inline(always)]
-    fn validate_value_bool(&self, value: &JsValue, field_type: &FieldType) -> bool {
+    fn validate_value_bool(&self, value: &JsValue, field_type: &FieldType, state: &mut RecursionState) -> bool {
+        if state.depth > self.max_depth {
+            return false;
+        }
+
         match field_type {
             FieldType::String => value.is_string(),
-            FieldType::Number => value.as_f64().is_some(),
+            FieldType::Number(constraint) => validate_numeric(value, constraint),
             FieldType::Boolean => value.as_bool().is_some(),
             FieldType::Array(item_type) => {
                 let Some(array) = value.dyn_ref::<Array>() else {
                     return false;
                 };
-                
-                self.validate_array_optimized(array, item_type)
+
+                state.depth += 1;
+                let result = self.validate_array_optimized(array, item_type, state);
+                state.depth -= 1;
+                result
             }
             FieldType::Object(nested_schema) => {
-                self.validate_object_bool(value, nested_schema)
+                self.validate_object_bool(value, nested_schema, state)
             }
             FieldType::Custom(type_name) => {
                 if let Some(custom_type) = self.custom_types.get(type_name) {
-                    self.validate_object_bool(value, custom_type)
+                    // validate_object_bool bumps state.depth itself, so a self-referential
+                    // custom type still fails cleanly once max_depth is exceeded.
+                    self.validate_object_bool(value, custom_type, state)
                 } else {
                     true
                 }
@@ -926,14 +1713,17 @@ impl DhiCore {
                     return false;
                 };
 
+                state.depth += 1;
                 // Iterate values directly to avoid building [key, value] pairs
                 let values = Object::values(obj);
                 for i in 0..values.length() {
                     let v = values.get(i);
-                    if !self.validate_value_bool(&v, value_type) {
+                    if !self.validate_value_bool(&v, value_type, state) {
+                        state.depth -= 1;
                         return false;
                     }
                 }
+                state.depth -= 1;
                 true
             }
             FieldType::Date => value.is_instance_of::<js_sys::Date>(),
@@ -957,10 +1747,10 @@ impl DhiCore {
                 // Try primitive types first (they're faster to validate)
                 let mut primitive_types = Vec::new();
                 let mut complex_types = Vec::new();
-                
+
                 for field_type in union_types {
                     match field_type {
-                        FieldType::String | FieldType::Number | FieldType::Boolean => {
+                        FieldType::String | FieldType::Number(_) | FieldType::Boolean => {
                             primitive_types.push(field_type);
                         }
                         _ => {
@@ -968,21 +1758,25 @@ impl DhiCore {
                         }
                     }
                 }
-                
+
+                state.depth += 1;
                 // Try primitives first
                 for field_type in primitive_types {
-                    if self.validate_value_bool(value, field_type) {
+                    if self.validate_value_bool(value, field_type, state) {
+                        state.depth -= 1;
                         return true;
                     }
                 }
-                
+
                 // Then try complex types
                 for field_type in complex_types {
-                    if self.validate_value_bool(value, field_type) {
+                    if self.validate_value_bool(value, field_type, state) {
+                        state.depth -= 1;
                         return true;
                     }
                 }
-                
+
+                state.depth -= 1;
                 false
             }
         }
@@ -1008,11 +1802,360 @@ impl DhiCore {
         if let Some(last_field) = self.schema.iter_mut().last() {
             last_field.1.field_type = match value_type {
                 "string" => FieldType::String,
-                "number" => FieldType::Number,
                 "boolean" => FieldType::Boolean,
-                // Add other types as needed
-                _ => FieldType::Any,
+                _ => numeric_kind_from_name(value_type)
+                    .map(|kind| FieldType::Number(NumericConstraint::unbounded(kind)))
+                    .unwrap_or(FieldType::Any),
             };
         }
     }
-} 
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    // A self-referential `Node { value: string, next?: Node }` schema, the
+    // same shape used in the chunk1-2 regression (falsely flagging a
+    // non-cyclic repeated Custom type as a cycle).
+    fn node_schema() -> DhiCore {
+        let mut core = DhiCore::new();
+        core.define_custom_type("Node".to_string()).unwrap();
+        core.add_field_to_custom_type("Node".to_string(), "value".to_string(), "string".to_string(), true).unwrap();
+        core.add_field_to_custom_type("Node".to_string(), "next".to_string(), "Node".to_string(), false).unwrap();
+        core.add_field("root".to_string(), "Node".to_string(), true).unwrap();
+        core
+    }
+
+    // Builds a `Node` chain `depth` levels deep: {value, next: {value, next: ...}}.
+    fn node_chain(depth: usize) -> JsValue {
+        let mut current = JsValue::UNDEFINED;
+        for i in (0..depth).rev() {
+            let obj = Object::new();
+            Reflect::set(&obj, &JsValue::from_str("value"), &JsValue::from_str(&i.to_string())).unwrap();
+            if !current.is_undefined() {
+                Reflect::set(&obj, &JsValue::from_str("next"), &current).unwrap();
+            }
+            current = obj.into();
+        }
+        current
+    }
+
+    fn issue_codes(issues: &Array) -> Vec<String> {
+        (0..issues.length())
+            .map(|i| {
+                Reflect::get(&issues.get(i), &JsValue::from_str("code"))
+                    .ok()
+                    .and_then(|v| v.as_string())
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+
+    #[wasm_bindgen_test]
+    fn deeply_nested_custom_type_hits_max_depth() {
+        let core = node_schema();
+        let root = Object::new();
+        Reflect::set(&root, &JsValue::from_str("root"), &node_chain(DEFAULT_MAX_DEPTH as usize + 5)).unwrap();
+
+        let issues = core.validate_collect(root.into()).unwrap();
+        assert!(issue_codes(&issues).iter().any(|code| code == "max_depth_exceeded"));
+    }
+
+    #[wasm_bindgen_test]
+    fn repeated_non_cyclic_custom_type_validates() {
+        let core = node_schema();
+        let root = Object::new();
+        Reflect::set(&root, &JsValue::from_str("root"), &node_chain(2)).unwrap();
+
+        let issues = core.validate_collect(root.into()).unwrap();
+        assert_eq!(issue_codes(&issues), Vec::<String>::new());
+    }
+
+    #[wasm_bindgen_test]
+    fn validate_collect_reports_every_missing_required_field() {
+        let mut core = DhiCore::new();
+        core.add_field("name".to_string(), "string".to_string(), true).unwrap();
+        core.add_field("age".to_string(), "number".to_string(), true).unwrap();
+
+        let issues = core.validate_collect(Object::new().into()).unwrap();
+        assert_eq!(issue_codes(&issues), vec!["missing", "missing"]);
+    }
+
+    #[wasm_bindgen_test]
+    fn validate_collect_reports_type_mismatch() {
+        let mut core = DhiCore::new();
+        core.add_field("name".to_string(), "string".to_string(), true).unwrap();
+
+        let obj = Object::new();
+        Reflect::set(&obj, &JsValue::from_str("name"), &JsValue::from_f64(42.0)).unwrap();
+
+        let issues = core.validate_collect(obj.into()).unwrap();
+        assert_eq!(issue_codes(&issues), vec!["type_mismatch"]);
+    }
+
+    #[wasm_bindgen_test]
+    fn validate_collect_not_in_enum_carries_offending_value() {
+        let mut core = DhiCore::new();
+        core.add_field("status".to_string(), "enum:active,inactive".to_string(), true).unwrap();
+
+        let obj = Object::new();
+        Reflect::set(&obj, &JsValue::from_str("status"), &JsValue::from_str("deleted")).unwrap();
+
+        let issues = core.validate_collect(obj.into()).unwrap();
+        assert_eq!(issue_codes(&issues), vec!["not_in_enum"]);
+
+        let value = Reflect::get(&issues.get(0), &JsValue::from_str("value")).ok()
+            .and_then(|v| v.as_string());
+        assert_eq!(value.as_deref(), Some("deleted"));
+    }
+
+    #[wasm_bindgen_test]
+    fn validate_collect_union_no_match() {
+        let mut core = DhiCore::new();
+        core.add_field("id".to_string(), "Union<string,number>".to_string(), true).unwrap();
+
+        let obj = Object::new();
+        Reflect::set(&obj, &JsValue::from_str("id"), &JsValue::from_bool(true)).unwrap();
+
+        let issues = core.validate_collect(obj.into()).unwrap();
+        assert_eq!(issue_codes(&issues), vec!["union_no_match"]);
+    }
+
+    fn with_x(n: f64) -> JsValue {
+        let obj = Object::new();
+        Reflect::set(&obj, &JsValue::from_str("x"), &JsValue::from_f64(n)).unwrap();
+        obj.into()
+    }
+
+    #[wasm_bindgen_test]
+    fn number_rejects_nan_and_infinity() {
+        let mut core = DhiCore::new();
+        core.add_field("x".to_string(), "number".to_string(), true).unwrap();
+
+        assert!(!core.validate(with_x(f64::NAN)).unwrap());
+        assert!(!core.validate(with_x(f64::INFINITY)).unwrap());
+        assert!(!core.validate(with_x(f64::NEG_INFINITY)).unwrap());
+        assert!(core.validate(with_x(42.0)).unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    fn int8_enforces_width_and_integrality() {
+        let mut core = DhiCore::new();
+        core.add_field("x".to_string(), "int8".to_string(), true).unwrap();
+
+        assert!(core.validate(with_x(127.0)).unwrap());
+        assert!(core.validate(with_x(-128.0)).unwrap());
+        assert!(!core.validate(with_x(128.0)).unwrap());
+        assert!(!core.validate(with_x(-129.0)).unwrap());
+        assert!(!core.validate(with_x(1.5)).unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    fn int16_int32_enforce_their_own_ranges() {
+        let mut core16 = DhiCore::new();
+        core16.add_field("x".to_string(), "int16".to_string(), true).unwrap();
+        assert!(core16.validate(with_x(32767.0)).unwrap());
+        assert!(!core16.validate(with_x(32768.0)).unwrap());
+
+        let mut core32 = DhiCore::new();
+        core32.add_field("x".to_string(), "int32".to_string(), true).unwrap();
+        assert!(core32.validate(with_x(2147483647.0)).unwrap());
+        assert!(!core32.validate(with_x(2147483648.0)).unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    fn uint32_rejects_negative_values() {
+        let mut core = DhiCore::new();
+        core.add_field("x".to_string(), "uint32".to_string(), true).unwrap();
+
+        assert!(core.validate(with_x(0.0)).unwrap());
+        assert!(!core.validate(with_x(-1.0)).unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    fn number_min_max_constraint_grammar_is_enforced() {
+        let mut core = DhiCore::new();
+        core.add_field("x".to_string(), "number(min=0,max=100)".to_string(), true).unwrap();
+
+        assert!(core.validate(with_x(0.0)).unwrap());
+        assert!(core.validate(with_x(100.0)).unwrap());
+        assert!(!core.validate(with_x(-1.0)).unwrap());
+        assert!(!core.validate(with_x(101.0)).unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    fn number_array_simd_path_applies_same_numeric_check() {
+        let mut core = DhiCore::new();
+        core.add_field("values".to_string(), "Array<number>".to_string(), true).unwrap();
+
+        let arr = Array::new();
+        arr.push(&JsValue::from_f64(1.0));
+        arr.push(&JsValue::from_f64(f64::NAN));
+        let obj = Object::new();
+        Reflect::set(&obj, &JsValue::from_str("values"), &arr).unwrap();
+
+        assert!(!core.validate(obj.into()).unwrap());
+    }
+
+    fn make_column(values: &[JsValue]) -> Array {
+        let arr = Array::new();
+        for v in values { arr.push(v); }
+        arr
+    }
+
+    fn column_failures(failures: &Array) -> Vec<(u32, String, String)> {
+        (0..failures.length())
+            .map(|i| {
+                let f = failures.get(i);
+                let row = Reflect::get(&f, &JsValue::from_str("row")).unwrap().as_f64().unwrap() as u32;
+                let field = Reflect::get(&f, &JsValue::from_str("field")).unwrap().as_string().unwrap();
+                let code = Reflect::get(&f, &JsValue::from_str("code")).unwrap().as_string().unwrap();
+                (row, field, code)
+            })
+            .collect()
+    }
+
+    #[wasm_bindgen_test]
+    fn validate_columns_reports_missing_required_field_by_row() {
+        let mut core = DhiCore::new();
+        core.add_field("name".to_string(), "string".to_string(), true).unwrap();
+        core.add_field("age".to_string(), "number".to_string(), true).unwrap();
+
+        // row 1 has an explicit undefined name; row 2 is missing from the
+        // (shorter) age column entirely.
+        let names = make_column(&[JsValue::from_str("a"), JsValue::UNDEFINED, JsValue::from_str("c")]);
+        let ages = make_column(&[JsValue::from_f64(1.0), JsValue::from_f64(2.0)]);
+
+        let columns = Object::new();
+        Reflect::set(&columns, &JsValue::from_str("name"), &names).unwrap();
+        Reflect::set(&columns, &JsValue::from_str("age"), &ages).unwrap();
+
+        let failures = column_failures(&core.validate_columns(columns.into()).unwrap());
+        assert_eq!(failures.len(), 2);
+        assert!(failures.contains(&(1, "name".to_string(), "missing".to_string())));
+        assert!(failures.contains(&(2, "age".to_string(), "missing".to_string())));
+    }
+
+    #[wasm_bindgen_test]
+    fn validate_columns_reports_type_mismatch_per_column() {
+        let mut core = DhiCore::new();
+        core.add_field("age".to_string(), "number".to_string(), true).unwrap();
+
+        let ages = make_column(&[JsValue::from_f64(1.0), JsValue::from_str("nope"), JsValue::from_f64(3.0)]);
+        let columns = Object::new();
+        Reflect::set(&columns, &JsValue::from_str("age"), &ages).unwrap();
+
+        let failures = column_failures(&core.validate_columns(columns.into()).unwrap());
+        assert_eq!(failures, vec![(1, "age".to_string(), "type_mismatch".to_string())]);
+    }
+
+    #[wasm_bindgen_test]
+    fn validate_columns_ragged_optional_column_is_not_missing() {
+        let mut core = DhiCore::new();
+        core.add_field("name".to_string(), "string".to_string(), false).unwrap();
+        core.add_field("age".to_string(), "number".to_string(), true).unwrap();
+
+        // name is optional and shorter than age's column; that's not an error.
+        let names = make_column(&[JsValue::from_str("only-one")]);
+        let ages = make_column(&[JsValue::from_f64(1.0), JsValue::from_f64(2.0), JsValue::from_f64(3.0)]);
+
+        let columns = Object::new();
+        Reflect::set(&columns, &JsValue::from_str("name"), &names).unwrap();
+        Reflect::set(&columns, &JsValue::from_str("age"), &ages).unwrap();
+
+        let failures = core.validate_columns(columns.into()).unwrap();
+        assert_eq!(failures.length(), 0);
+    }
+
+    fn avro_field(name: &str, type_val: JsValue) -> JsValue {
+        let obj = Object::new();
+        Reflect::set(&obj, &JsValue::from_str("name"), &JsValue::from_str(name)).unwrap();
+        Reflect::set(&obj, &JsValue::from_str("type"), &type_val).unwrap();
+        obj.into()
+    }
+
+    fn avro_record(name: Option<&str>, fields: &[JsValue]) -> JsValue {
+        let obj = Object::new();
+        if let Some(name) = name {
+            Reflect::set(&obj, &JsValue::from_str("name"), &JsValue::from_str(name)).unwrap();
+        }
+        let arr = Array::new();
+        for f in fields { arr.push(f); }
+        Reflect::set(&obj, &JsValue::from_str("fields"), &arr).unwrap();
+        obj.into()
+    }
+
+    fn avro_complex(kind: &str, extra_key: &str, extra_val: JsValue) -> JsValue {
+        let obj = Object::new();
+        Reflect::set(&obj, &JsValue::from_str("type"), &JsValue::from_str(kind)).unwrap();
+        Reflect::set(&obj, &JsValue::from_str(extra_key), &extra_val).unwrap();
+        obj.into()
+    }
+
+    fn avro_nullable_union(inner: &str) -> JsValue {
+        let arr = Array::new();
+        arr.push(&JsValue::from_str("null"));
+        arr.push(&JsValue::from_str(inner));
+        arr.into()
+    }
+
+    #[wasm_bindgen_test]
+    fn load_avro_schema_maps_record_array_map_enum_union() {
+        let mut core = DhiCore::new();
+
+        let symbols = Array::new();
+        symbols.push(&JsValue::from_str("active"));
+        symbols.push(&JsValue::from_str("inactive"));
+        let status_type = avro_complex("enum", "symbols", symbols.into());
+        let tags_type = avro_complex("array", "items", JsValue::from_str("string"));
+        let meta_type = avro_complex("map", "values", JsValue::from_str("string"));
+
+        let schema = avro_record(Some("Person"), &[
+            avro_field("name", JsValue::from_str("string")),
+            avro_field("age", avro_nullable_union("int")),
+            avro_field("tags", tags_type),
+            avro_field("meta", meta_type),
+            avro_field("status", status_type),
+        ]);
+        core.load_avro_schema(schema).unwrap();
+
+        let obj = Object::new();
+        Reflect::set(&obj, &JsValue::from_str("name"), &JsValue::from_str("Ada")).unwrap();
+        // the nullable "age" field is omitted entirely -- that must be accepted.
+        let tags = Array::new();
+        tags.push(&JsValue::from_str("a"));
+        Reflect::set(&obj, &JsValue::from_str("tags"), &tags).unwrap();
+        let meta = Object::new();
+        Reflect::set(&meta, &JsValue::from_str("k"), &JsValue::from_str("v")).unwrap();
+        Reflect::set(&obj, &JsValue::from_str("meta"), &meta).unwrap();
+        Reflect::set(&obj, &JsValue::from_str("status"), &JsValue::from_str("active")).unwrap();
+
+        assert!(core.validate(obj.clone().into()).unwrap());
+
+        // an explicit JSON null for the nullable field must validate too, not just omission.
+        Reflect::set(&obj, &JsValue::from_str("age"), &JsValue::NULL).unwrap();
+        assert!(core.validate(obj.clone().into()).unwrap());
+
+        // a present, correctly-typed value for the nullable field is also fine.
+        Reflect::set(&obj, &JsValue::from_str("age"), &JsValue::from_f64(30.0)).unwrap();
+        assert!(core.validate(obj.into()).unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    fn load_avro_schema_named_record_self_reference_validates() {
+        let mut core = DhiCore::new();
+
+        let schema = avro_record(Some("Node"), &[
+            avro_field("value", JsValue::from_str("string")),
+            avro_field("next", avro_nullable_union("Node")),
+        ]);
+        core.load_avro_schema(schema).unwrap();
+
+        assert!(core.validate(node_chain(3)).unwrap());
+        assert!(!core.validate(Object::new().into()).unwrap());
+    }
+}
\ No newline at end of file